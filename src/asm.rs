@@ -0,0 +1,415 @@
+use std::collections::HashMap;
+use std::fmt;
+
+const BASE_ADDR: u16 = 0x200;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AsmError {
+	UnknownMnemonic { line: usize, text: String },
+	UnknownLabel { line: usize, name: String },
+	DuplicateLabel { line: usize, name: String },
+	BadOperand { line: usize, text: String },
+}
+
+impl fmt::Display for AsmError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			AsmError::UnknownMnemonic { line, text } => write!(f, "line {line}: unknown mnemonic '{text}'"),
+			AsmError::UnknownLabel { line, name } => write!(f, "line {line}: unknown label '{name}'"),
+			AsmError::DuplicateLabel { line, name } => write!(f, "line {line}: duplicate label '{name}'"),
+			AsmError::BadOperand { line, text } => write!(f, "line {line}: bad operand '{text}'"),
+		}
+	}
+}
+
+impl std::error::Error for AsmError {}
+
+struct Line<'a> {
+	number: usize,
+	label: Option<&'a str>,
+	instruction: Option<&'a str>,
+}
+
+// Assembles CHIP-8 mnemonics into a big-endian [u8] image loadable at 0x200.
+// Two passes: the first records label addresses, the second resolves operands
+// (including label references in jump/call/LD I operands) and emits opcodes.
+pub fn assemble(source: &str) -> Result<Vec<u8>, AsmError> {
+	let lines = split_lines(source);
+
+	let mut labels = HashMap::new();
+	let mut pc = BASE_ADDR;
+	for line in &lines {
+		if let Some(name) = line.label {
+			let duplicate = labels.insert(name.to_string(), pc).is_some();
+			if duplicate {
+				return Err(AsmError::DuplicateLabel { line: line.number, name: name.to_string() });
+			}
+		}
+		if line.instruction.is_some() {
+			pc += 2;
+		}
+	}
+
+	let mut out = Vec::new();
+	for line in &lines {
+		let Some(text) = line.instruction else { continue };
+		let opcode = encode_instruction(text, line.number, &labels)?;
+		out.push((opcode >> 8) as u8);
+		out.push((opcode & 0xFF) as u8);
+	}
+
+	Ok(out)
+}
+
+// Disassembles a raw image back into (addr, opcode, mnemonic) tuples, using
+// the same opcode layout assemble() understands.
+pub fn disassemble(bytes: &[u8], base_addr: u16) -> Vec<(u16, u16, String)> {
+	bytes.chunks(2)
+		.filter(|chunk| chunk.len() == 2)
+		.enumerate()
+		.map(|(idx, chunk)| {
+			let addr = base_addr + (idx as u16) * 2;
+			let opcode = ((chunk[0] as u16) << 8) | chunk[1] as u16;
+			(addr, opcode, decode_instruction(opcode))
+		})
+		.collect()
+}
+
+fn split_lines(source: &str) -> Vec<Line<'_>> {
+	source.lines().enumerate().filter_map(|(idx, raw)| {
+		let number = idx + 1;
+		let without_comment = match raw.find(';') {
+			Some(pos) => &raw[..pos],
+			None => raw,
+		};
+		let trimmed = without_comment.trim();
+		if trimmed.is_empty() {
+			return None;
+		}
+
+		let (label, rest) = match trimmed.find(':') {
+			Some(pos) => (Some(trimmed[..pos].trim()), trimmed[(pos + 1)..].trim()),
+			None => (None, trimmed),
+		};
+
+		Some(Line {
+			number,
+			label,
+			instruction: if rest.is_empty() { None } else { Some(rest) },
+		})
+	}).collect()
+}
+
+fn encode_instruction(text: &str, line: usize, labels: &HashMap<String, u16>) -> Result<u16, AsmError> {
+	let mut parts = text.splitn(2, char::is_whitespace);
+	let mnemonic = parts.next().unwrap_or("").to_ascii_uppercase();
+	let operand_str = parts.next().unwrap_or("").trim();
+	let operands: Vec<&str> = if operand_str.is_empty() {
+		Vec::new()
+	} else {
+		operand_str.split(',').map(str::trim).collect()
+	};
+
+	let bad = || AsmError::BadOperand { line, text: text.to_string() };
+
+	match mnemonic.as_str() {
+		"CLS" => Ok(0x00E0),
+		"RET" => Ok(0x00EE),
+		"JP" => match operands.as_slice() {
+			[addr] => Ok(0x1000 | resolve_addr(addr, line, labels)?),
+			[reg, addr] if reg.eq_ignore_ascii_case("V0") => Ok(0xB000 | resolve_addr(addr, line, labels)?),
+			_ => Err(bad()),
+		},
+		"CALL" => match operands.as_slice() {
+			[addr] => Ok(0x2000 | resolve_addr(addr, line, labels)?),
+			_ => Err(bad()),
+		},
+		"SE" => match operands.as_slice() {
+			[vx, op2] => {
+				let x = parse_vx(vx, line)?;
+				if let Ok(y) = parse_vx(op2, line) {
+					Ok(0x5000 | (x << 8) | (y << 4))
+				} else {
+					Ok(0x3000 | (x << 8) | parse_byte(op2, line)? as u16)
+				}
+			}
+			_ => Err(bad()),
+		},
+		"SNE" => match operands.as_slice() {
+			[vx, op2] => {
+				let x = parse_vx(vx, line)?;
+				if let Ok(y) = parse_vx(op2, line) {
+					Ok(0x9000 | (x << 8) | (y << 4))
+				} else {
+					Ok(0x4000 | (x << 8) | parse_byte(op2, line)? as u16)
+				}
+			}
+			_ => Err(bad()),
+		},
+		"LD" => encode_ld(&operands, line, labels),
+		"ADD" => match operands.as_slice() {
+			[reg, vx] if reg.eq_ignore_ascii_case("I") => Ok(0xF01E | (parse_vx(vx, line)? << 8)),
+			[vx, op2] => {
+				let x = parse_vx(vx, line)?;
+				if let Ok(y) = parse_vx(op2, line) {
+					Ok(0x8004 | (x << 8) | (y << 4))
+				} else {
+					Ok(0x7000 | (x << 8) | parse_byte(op2, line)? as u16)
+				}
+			}
+			_ => Err(bad()),
+		},
+		"OR" => binary_8xy(&operands, line, 0x1),
+		"AND" => binary_8xy(&operands, line, 0x2),
+		"XOR" => binary_8xy(&operands, line, 0x3),
+		"SUB" => binary_8xy(&operands, line, 0x5),
+		"SUBN" => binary_8xy(&operands, line, 0x7),
+		"SHR" => shift_8xy(&operands, line, 0x6),
+		"SHL" => shift_8xy(&operands, line, 0xE),
+		"RND" => match operands.as_slice() {
+			[vx, byte] => Ok(0xC000 | (parse_vx(vx, line)? << 8) | parse_byte(byte, line)? as u16),
+			_ => Err(bad()),
+		},
+		"DRW" => match operands.as_slice() {
+			[vx, vy, n] => Ok(0xD000 | (parse_vx(vx, line)? << 8) | (parse_vx(vy, line)? << 4) | parse_nibble(n, line)?),
+			_ => Err(bad()),
+		},
+		"SKP" => match operands.as_slice() {
+			[vx] => Ok(0xE09E | (parse_vx(vx, line)? << 8)),
+			_ => Err(bad()),
+		},
+		"SKNP" => match operands.as_slice() {
+			[vx] => Ok(0xE0A1 | (parse_vx(vx, line)? << 8)),
+			_ => Err(bad()),
+		},
+		_ => Err(AsmError::UnknownMnemonic { line, text: mnemonic }),
+	}
+}
+
+fn encode_ld(operands: &[&str], line: usize, labels: &HashMap<String, u16>) -> Result<u16, AsmError> {
+	let bad = || AsmError::BadOperand { line, text: operands.join(", ") };
+	let [dst, src] = operands else { return Err(bad()) };
+
+	if dst.eq_ignore_ascii_case("I") {
+		Ok(0xA000 | resolve_addr(src, line, labels)?)
+	} else if dst.eq_ignore_ascii_case("DT") {
+		Ok(0xF015 | (parse_vx(src, line)? << 8))
+	} else if dst.eq_ignore_ascii_case("ST") {
+		Ok(0xF018 | (parse_vx(src, line)? << 8))
+	} else if dst.eq_ignore_ascii_case("F") {
+		Ok(0xF029 | (parse_vx(src, line)? << 8))
+	} else if dst.eq_ignore_ascii_case("B") {
+		Ok(0xF033 | (parse_vx(src, line)? << 8))
+	} else if dst.eq_ignore_ascii_case("[I]") {
+		Ok(0xF055 | (parse_vx(src, line)? << 8))
+	} else {
+		let x = parse_vx(dst, line)?;
+		if src.eq_ignore_ascii_case("DT") {
+			Ok(0xF007 | (x << 8))
+		} else if src.eq_ignore_ascii_case("K") {
+			Ok(0xF00A | (x << 8))
+		} else if src.eq_ignore_ascii_case("[I]") {
+			Ok(0xF065 | (x << 8))
+		} else if let Ok(y) = parse_vx(src, line) {
+			Ok(0x8000 | (x << 8) | (y << 4))
+		} else {
+			Ok(0x6000 | (x << 8) | parse_byte(src, line)? as u16)
+		}
+	}
+}
+
+fn binary_8xy(operands: &[&str], line: usize, op: u16) -> Result<u16, AsmError> {
+	match operands {
+		[vx, vy] => Ok(0x8000 | (parse_vx(vx, line)? << 8) | (parse_vx(vy, line)? << 4) | op),
+		_ => Err(AsmError::BadOperand { line, text: operands.join(", ") }),
+	}
+}
+
+fn shift_8xy(operands: &[&str], line: usize, op: u16) -> Result<u16, AsmError> {
+	match operands {
+		[vx] => Ok(0x8000 | (parse_vx(vx, line)? << 8) | op),
+		[vx, vy] => Ok(0x8000 | (parse_vx(vx, line)? << 8) | (parse_vx(vy, line)? << 4) | op),
+		_ => Err(AsmError::BadOperand { line, text: operands.join(", ") }),
+	}
+}
+
+fn parse_vx(s: &str, line: usize) -> Result<u16, AsmError> {
+	let s = s.trim();
+	if s.len() >= 2 && (s.starts_with('V') || s.starts_with('v')) {
+		let parsed = u16::from_str_radix(&s[1..], 16);
+		if let Ok(n @ 0..=0xF) = parsed {
+			return Ok(n);
+		}
+	}
+	Err(AsmError::BadOperand { line, text: s.to_string() })
+}
+
+fn parse_number(s: &str) -> Option<u32> {
+	let s = s.trim();
+	match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+		Some(hex) => u32::from_str_radix(hex, 16).ok(),
+		None => s.parse::<u32>().ok(),
+	}
+}
+
+fn parse_byte(s: &str, line: usize) -> Result<u8, AsmError> {
+	parse_number(s)
+		.filter(|&n| n <= 0xFF)
+		.map(|n| n as u8)
+		.ok_or_else(|| AsmError::BadOperand { line, text: s.to_string() })
+}
+
+fn parse_nibble(s: &str, line: usize) -> Result<u16, AsmError> {
+	parse_number(s)
+		.filter(|&n| n <= 0xF)
+		.map(|n| n as u16)
+		.ok_or_else(|| AsmError::BadOperand { line, text: s.to_string() })
+}
+
+fn resolve_addr(s: &str, line: usize, labels: &HashMap<String, u16>) -> Result<u16, AsmError> {
+	if let Some(&addr) = labels.get(s) {
+		return Ok(addr & 0x0FFF);
+	}
+	match parse_number(s) {
+		Some(n) if n <= 0x0FFF => Ok(n as u16),
+		Some(_) => Err(AsmError::BadOperand { line, text: s.to_string() }),
+		None => Err(AsmError::UnknownLabel { line, name: s.to_string() }),
+	}
+}
+
+fn decode_instruction(inst: u16) -> String {
+	let x = (inst >> 8) & 0x0F;
+	let y = (inst >> 4) & 0x0F;
+	let n = inst & 0x0F;
+	let nn = inst & 0xFF;
+	let nnn = inst & 0x0FFF;
+
+	if inst == 0x00E0 {
+		"CLS".to_string()
+	} else if inst == 0x00EE {
+		"RET".to_string()
+	} else if (inst & 0xF000) == 0x1000 {
+		format!("JP {nnn:#05X}")
+	} else if (inst & 0xF000) == 0x2000 {
+		format!("CALL {nnn:#05X}")
+	} else if (inst & 0xF000) == 0x3000 {
+		format!("SE V{x:X}, {nn:#04X}")
+	} else if (inst & 0xF000) == 0x4000 {
+		format!("SNE V{x:X}, {nn:#04X}")
+	} else if (inst & 0xF000) == 0x5000 {
+		format!("SE V{x:X}, V{y:X}")
+	} else if (inst & 0xF000) == 0x6000 {
+		format!("LD V{x:X}, {nn:#04X}")
+	} else if (inst & 0xF000) == 0x7000 {
+		format!("ADD V{x:X}, {nn:#04X}")
+	} else if (inst & 0xF000) == 0x8000 {
+		match n {
+			0x0 => format!("LD V{x:X}, V{y:X}"),
+			0x1 => format!("OR V{x:X}, V{y:X}"),
+			0x2 => format!("AND V{x:X}, V{y:X}"),
+			0x3 => format!("XOR V{x:X}, V{y:X}"),
+			0x4 => format!("ADD V{x:X}, V{y:X}"),
+			0x5 => format!("SUB V{x:X}, V{y:X}"),
+			0x6 => format!("SHR V{x:X}, V{y:X}"),
+			0x7 => format!("SUBN V{x:X}, V{y:X}"),
+			0xE => format!("SHL V{x:X}, V{y:X}"),
+			_ => format!("DW {inst:#06X}"),
+		}
+	} else if (inst & 0xF000) == 0x9000 {
+		format!("SNE V{x:X}, V{y:X}")
+	} else if (inst & 0xF000) == 0xA000 {
+		format!("LD I, {nnn:#05X}")
+	} else if (inst & 0xF000) == 0xB000 {
+		format!("JP V0, {nnn:#05X}")
+	} else if (inst & 0xF000) == 0xC000 {
+		format!("RND V{x:X}, {nn:#04X}")
+	} else if (inst & 0xF000) == 0xD000 {
+		format!("DRW V{x:X}, V{y:X}, {n:#03X}")
+	} else if (inst & 0xF0FF) == 0xE09E {
+		format!("SKP V{x:X}")
+	} else if (inst & 0xF0FF) == 0xE0A1 {
+		format!("SKNP V{x:X}")
+	} else if (inst & 0xF0FF) == 0xF007 {
+		format!("LD V{x:X}, DT")
+	} else if (inst & 0xF0FF) == 0xF00A {
+		format!("LD V{x:X}, K")
+	} else if (inst & 0xF0FF) == 0xF015 {
+		format!("LD DT, V{x:X}")
+	} else if (inst & 0xF0FF) == 0xF018 {
+		format!("LD ST, V{x:X}")
+	} else if (inst & 0xF0FF) == 0xF01E {
+		format!("ADD I, V{x:X}")
+	} else if (inst & 0xF0FF) == 0xF029 {
+		format!("LD F, V{x:X}")
+	} else if (inst & 0xF0FF) == 0xF033 {
+		format!("LD B, V{x:X}")
+	} else if (inst & 0xF0FF) == 0xF055 {
+		format!("LD [I], V{x:X}")
+	} else if (inst & 0xF0FF) == 0xF065 {
+		format!("LD V{x:X}, [I]")
+	} else {
+		format!("DW {inst:#06X}")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn assembles_labels_and_resolves_forward_and_backward_references() {
+		let source = "
+			start:
+				CLS
+				LD V0, 0x0A
+				ADD V0, V1
+				SE V0, V1
+				JP end
+				JP start
+			end:
+				RET
+		";
+
+		let bytes = assemble(source).unwrap();
+		assert_eq!(bytes, vec![
+			0x00, 0xE0, // CLS
+			0x60, 0x0A, // LD V0, 0x0A
+			0x80, 0x14, // ADD V0, V1
+			0x50, 0x10, // SE V0, V1
+			0x12, 0x0C, // JP end      (0x20C)
+			0x12, 0x00, // JP start    (0x200)
+			0x00, 0xEE, // RET
+		]);
+	}
+
+	#[test]
+	fn assemble_reports_line_number_on_unknown_mnemonic() {
+		let err = assemble("CLS\nNOPE V0, 0x01\n").unwrap_err();
+		assert_eq!(err, AsmError::UnknownMnemonic { line: 2, text: "NOPE".to_string() });
+	}
+
+	#[test]
+	fn assemble_reports_unknown_label() {
+		let err = assemble("JP missing\n").unwrap_err();
+		assert_eq!(err, AsmError::UnknownLabel { line: 1, name: "missing".to_string() });
+	}
+
+	#[test]
+	fn assemble_reports_bad_operand_for_out_of_range_numeric_address() {
+		let err = assemble("JP 0x2000\n").unwrap_err();
+		assert_eq!(err, AsmError::BadOperand { line: 1, text: "0x2000".to_string() });
+	}
+
+	#[test]
+	fn disassemble_round_trips_through_assemble() {
+		let source = "start: CLS\nLD V0, 0x2A\nDRW V0, V1, 0x5\nJP start\n";
+		let bytes = assemble(source).unwrap();
+
+		let decoded = disassemble(&bytes, 0x200);
+		let mnemonics: Vec<&str> = decoded.iter().map(|(_, _, text)| text.as_str()).collect();
+		assert_eq!(mnemonics, vec!["CLS", "LD V0, 0x2A", "DRW V0, V1, 0x5", "JP 0x200"]);
+
+		let reassembled = assemble(&mnemonics.join("\n")).unwrap();
+		assert_eq!(reassembled, bytes);
+	}
+}
@@ -1,7 +1,67 @@
 use std::fs;
+use std::io::Read;
 use byteorder::ReadBytesExt;
+use byteorder::WriteBytesExt;
 use byteorder::BigEndian;
 
+const SAVE_MAGIC: &[u8; 4] = b"C8ST";
+const SAVE_VERSION: u8 = 1;
+const SCREEN_BYTES: usize = (64 * 32) / 8;
+
+#[derive(Debug)]
+pub enum LoadStateError {
+	BadMagic,
+	UnsupportedVersion(u8),
+	Truncated,
+	InvalidStackPointer(u8),
+}
+
+const FONT_ADDR: usize = 0x50;
+const FONT: [u8; 80] = [
+	0xF0, 0x90, 0x90, 0x90, 0xF0, // 0
+	0x20, 0x60, 0x20, 0x20, 0x70, // 1
+	0xF0, 0x10, 0xF0, 0x80, 0xF0, // 2
+	0xF0, 0x10, 0xF0, 0x10, 0xF0, // 3
+	0x90, 0x90, 0xF0, 0x10, 0x10, // 4
+	0xF0, 0x80, 0xF0, 0x10, 0xF0, // 5
+	0xF0, 0x80, 0xF0, 0x90, 0xF0, // 6
+	0xF0, 0x10, 0x20, 0x40, 0x40, // 7
+	0xF0, 0x90, 0xF0, 0x90, 0xF0, // 8
+	0xF0, 0x90, 0xF0, 0x10, 0xF0, // 9
+	0xF0, 0x90, 0xF0, 0x90, 0x90, // A
+	0xE0, 0x90, 0xE0, 0x90, 0xE0, // B
+	0xF0, 0x80, 0x80, 0x80, 0xF0, // C
+	0xE0, 0x90, 0x90, 0x90, 0xE0, // D
+	0xF0, 0x80, 0xF0, 0x80, 0xF0, // E
+	0xF0, 0x80, 0xF0, 0x80, 0x80, // F
+];
+
+pub struct Quirks {
+	pub shift_uses_vy: bool,
+	pub load_store_increments_i: bool,
+	pub jump_with_vx: bool,
+}
+
+impl Quirks {
+	// Original COSMAC VIP interpreter behavior.
+	pub fn cosmac_vip() -> Self {
+		Self {
+			shift_uses_vy: true,
+			load_store_increments_i: true,
+			jump_with_vx: false,
+		}
+	}
+
+	// Super-CHIP behavior.
+	pub fn schip() -> Self {
+		Self {
+			shift_uses_vy: false,
+			load_store_increments_i: false,
+			jump_with_vx: true,
+		}
+	}
+}
+
 pub struct Chip {
 	pub screen: [bool; 64*32],
 	memory: [u8; 0x1000],
@@ -12,10 +72,18 @@ pub struct Chip {
 	stack: [u16; 16],
 	sp: usize,
 	pc: u16,
+	seed: u64,
+	keys: [bool; 16],
+	prev_keys: [bool; 16],
+	quirks: Quirks,
 }
 
 impl Chip {
 	pub fn new() -> Self {
+		Self::with_seed(0x2545F4914F6CDD1D)
+	}
+
+	pub fn with_seed(seed: u64) -> Self {
 		Self {
 			screen: [false; 64*32],
 			memory: [0; 0x1000],
@@ -26,16 +94,34 @@ impl Chip {
 			stack: [0; 16],
 			sp: 0,
 			pc: 0,
+			seed,
+			keys: [false; 16],
+			prev_keys: [false; 16],
+			quirks: Quirks::cosmac_vip(),
 		}
 	}
 
+	pub fn set_quirks(&mut self, quirks: Quirks) {
+		self.quirks = quirks;
+	}
+
+	pub fn key_down(&mut self, key: u8) {
+		self.keys[key as usize] = true;
+	}
+
+	pub fn key_up(&mut self, key: u8) {
+		self.keys[key as usize] = false;
+	}
+
 	pub fn load(&mut self, filename: &str) {
         let program = fs::read(filename).expect(filename);
         self.memory.fill(0);
+        self.memory[FONT_ADDR..(FONT_ADDR + FONT.len())].copy_from_slice(&FONT);
         self.memory[0x200..(0x200 + program.len())].copy_from_slice(&program);
 	}
 
 	pub fn reset(&mut self) {
+		self.memory[FONT_ADDR..(FONT_ADDR + FONT.len())].copy_from_slice(&FONT);
 		self.v.fill(0);
 		self.i = 0;
 		self.delay_timer = 0;
@@ -45,6 +131,103 @@ impl Chip {
 		self.pc = 0x200;
 	}
 
+	// Decrements delay_timer/sound_timer toward zero; call at 60 Hz regardless
+	// of how many tick() instruction steps run per frame.
+	pub fn tick_timers(&mut self) {
+		self.delay_timer = self.delay_timer.saturating_sub(1);
+		self.sound_timer = self.sound_timer.saturating_sub(1);
+	}
+
+	pub fn is_beeping(&self) -> bool {
+		self.sound_timer > 0
+	}
+
+	pub fn save_state(&self) -> Vec<u8> {
+		let mut out = Vec::new();
+		out.extend_from_slice(SAVE_MAGIC);
+		out.push(SAVE_VERSION);
+		out.extend_from_slice(&self.memory);
+		out.extend_from_slice(&self.v);
+		out.write_u16::<BigEndian>(self.i).unwrap();
+		out.write_u16::<BigEndian>(self.delay_timer).unwrap();
+		out.write_u16::<BigEndian>(self.sound_timer).unwrap();
+		for &addr in &self.stack {
+			out.write_u16::<BigEndian>(addr).unwrap();
+		}
+		out.push(self.sp as u8);
+		out.write_u16::<BigEndian>(self.pc).unwrap();
+		for byte_bits in self.screen.chunks(8) {
+			let mut byte = 0u8;
+			for (bit, &pixel) in byte_bits.iter().enumerate() {
+				if pixel {
+					byte |= 1 << (7 - bit);
+				}
+			}
+			out.push(byte);
+		}
+		out
+	}
+
+	pub fn load_state(&mut self, data: &[u8]) -> Result<(), LoadStateError> {
+		let mut cursor = data;
+
+		if cursor.len() < SAVE_MAGIC.len() + 1 || &cursor[..SAVE_MAGIC.len()] != SAVE_MAGIC {
+			return Err(LoadStateError::BadMagic);
+		}
+		cursor = &cursor[SAVE_MAGIC.len()..];
+
+		let version = cursor[0];
+		if version != SAVE_VERSION {
+			return Err(LoadStateError::UnsupportedVersion(version));
+		}
+		cursor = &cursor[1..];
+
+		let mut memory = [0u8; 0x1000];
+		cursor.read_exact(&mut memory).map_err(|_| LoadStateError::Truncated)?;
+
+		let mut v = [0u8; 0x10];
+		cursor.read_exact(&mut v).map_err(|_| LoadStateError::Truncated)?;
+
+		let i = cursor.read_u16::<BigEndian>().map_err(|_| LoadStateError::Truncated)?;
+		let delay_timer = cursor.read_u16::<BigEndian>().map_err(|_| LoadStateError::Truncated)?;
+		let sound_timer = cursor.read_u16::<BigEndian>().map_err(|_| LoadStateError::Truncated)?;
+
+		let mut stack = [0u16; 16];
+		for slot in stack.iter_mut() {
+			*slot = cursor.read_u16::<BigEndian>().map_err(|_| LoadStateError::Truncated)?;
+		}
+
+		let sp_byte = cursor.read_u8().map_err(|_| LoadStateError::Truncated)?;
+		if sp_byte as usize > stack.len() {
+			return Err(LoadStateError::InvalidStackPointer(sp_byte));
+		}
+		let sp = sp_byte as usize;
+		let pc = cursor.read_u16::<BigEndian>().map_err(|_| LoadStateError::Truncated)?;
+
+		let mut screen_bytes = [0u8; SCREEN_BYTES];
+		cursor.read_exact(&mut screen_bytes).map_err(|_| LoadStateError::Truncated)?;
+
+		if !cursor.is_empty() {
+			return Err(LoadStateError::Truncated);
+		}
+
+		self.memory = memory;
+		self.v = v;
+		self.i = i;
+		self.delay_timer = delay_timer;
+		self.sound_timer = sound_timer;
+		self.stack = stack;
+		self.sp = sp;
+		self.pc = pc;
+		for (idx, byte) in screen_bytes.iter().enumerate() {
+			for bit in 0..8 {
+				self.screen[idx * 8 + bit] = (byte & (1 << (7 - bit))) != 0;
+			}
+		}
+
+		Ok(())
+	}
+
 	pub fn tick(&mut self) {
 		assert!((self.pc % 2) == 0);
 		let inst = self.next_instruction();
@@ -108,9 +291,10 @@ impl Chip {
 					self.v[x] = val;
 					self.v[0xF] = if sub_cmp { 1 } else { 0 };
 				}
-				0x6 => {                      // SHR Vx
-					let carry = self.v[y] & 1;
-					self.v[x] = self.v[y] >> 1;
+				0x6 => {                      // SHR Vx {, Vy}
+					let src = if self.quirks.shift_uses_vy { self.v[y] } else { self.v[x] };
+					let carry = src & 1;
+					self.v[x] = src >> 1;
 					self.v[0xF] = carry;
 				}
 				0x7 => {                      // SUBN Vx, Vy
@@ -119,9 +303,10 @@ impl Chip {
 					self.v[x] = val;
 					self.v[0xF] = if sub_cmp { 1 } else { 0 };
 				}
-				0xE => {                      // SHL Vx
-					let carry = (self.v[y] >> 7) & 1;
-					self.v[x] = self.v[y] << 1;
+				0xE => {                      // SHL Vx {, Vy}
+					let src = if self.quirks.shift_uses_vy { self.v[y] } else { self.v[x] };
+					let carry = (src >> 7) & 1;
+					self.v[x] = src << 1;
 					self.v[0xF] = carry;
 				}
 				_ => todo!(),
@@ -134,27 +319,77 @@ impl Chip {
 			}
 		} else if (inst & 0xF000) == 0xA000 { // LD I, addr
 			self.i = inst & 0x0FFF;
+		} else if (inst & 0xF000) == 0xB000 { // JP V0, addr
+			let addr = inst & 0x0FFF;
+			if self.quirks.jump_with_vx {
+				let x = ((addr >> 8) & 0x0F) as usize;
+				self.pc = (addr + self.v[x] as u16) & 0x0FFF;
+			} else {
+				self.pc = (addr + self.v[0] as u16) & 0x0FFF;
+			}
+		} else if (inst & 0xF000) == 0xC000 { // RND Vx, byte
+			let x = ((inst >> 8) & 0x0F) as usize;
+			let byte = (inst & 0xFF) as u8;
+			self.v[x] = self.next_rand_byte() & byte;
 		} else if (inst & 0xF000) == 0xD000 { // DRW Vx, Vy, nibble
-			let x = self.v[((inst >> 8) & 0x0F) as usize] as usize;
-			let y = self.v[((inst >> 4) & 0x0F) as usize] as usize;
+			let x0 = self.v[((inst >> 8) & 0x0F) as usize] as usize % 64;
+			let y0 = self.v[((inst >> 4) & 0x0F) as usize] as usize % 32;
 			let nibble = (inst & 0x0F) as usize;
 
+			self.v[0xF] = 0;
 			for row in 0..nibble {
+				let y = y0 + row;
+				if y >= 32 {
+					break;
+				}
 				let byte = self.memory[self.i as usize + row];
-				let offset = (row + y) * 64 + x;
+				let offset = y * 64;
 				for col in 0..8 {
-					let pixel = if (byte & (1 << (7 - col))) > 0 {
-						true
-					} else {
-						false
-					};
-
-					self.screen[offset + col] ^= pixel;
+					let x = x0 + col;
+					if x >= 64 {
+						break;
+					}
+					let pixel = (byte & (1 << (7 - col))) > 0;
+					if pixel && self.screen[offset + x] {
+						self.v[0xF] = 1;
+					}
+					self.screen[offset + x] ^= pixel;
+				}
+			}
+		} else if (inst & 0xF0FF) == 0xE09E { // SKP Vx
+			let x = ((inst >> 8) & 0x0F) as usize;
+			if self.keys[(self.v[x] & 0x0F) as usize] {
+				self.pc += 2;
+			}
+		} else if (inst & 0xF0FF) == 0xE0A1 { // SKNP Vx
+			let x = ((inst >> 8) & 0x0F) as usize;
+			if !self.keys[(self.v[x] & 0x0F) as usize] {
+				self.pc += 2;
+			}
+		} else if (inst & 0xF0FF) == 0xF007 { // LD Vx, DT
+			let x = ((inst >> 8) & 0x0F) as usize;
+			self.v[x] = self.delay_timer as u8;
+		} else if (inst & 0xF0FF) == 0xF00A { // LD Vx, K
+			let x = ((inst >> 8) & 0x0F) as usize;
+			match (0..16).find(|&k| self.keys[k] && !self.prev_keys[k]) {
+				Some(k) => self.v[x] = k as u8,
+				None => {
+					self.pc -= 2;
+					self.prev_keys = self.keys;
+					return;
 				}
 			}
-			// TODO: handle vF
+		} else if (inst & 0xF0FF) == 0xF015 { // LD DT, Vx
+			let x = ((inst >> 8) & 0x0F) as usize;
+			self.delay_timer = self.v[x] as u16;
+		} else if (inst & 0xF0FF) == 0xF018 { // LD ST, Vx
+			let x = ((inst >> 8) & 0x0F) as usize;
+			self.sound_timer = self.v[x] as u16;
 		} else if (inst & 0xF0FF) == 0xF01E { // ADD I, Vx
 			self.i += self.v[((inst >> 8) & 0x0F) as usize] as u16;
+		} else if (inst & 0xF0FF) == 0xF029 { // LD F, Vx
+			let x = ((inst >> 8) & 0x0F) as usize;
+			self.i = FONT_ADDR as u16 + (self.v[x] & 0x0F) as u16 * 5;
 		} else if (inst & 0xF0FF) == 0xF033 { // LD B, Vx
 			let x = self.v[((inst >> 8) & 0x0F) as usize];
 			let addr = self.i as usize;
@@ -167,15 +402,23 @@ impl Chip {
 			for i in 0..(x+1) {
 				self.memory[addr + i] = self.v[i];
 			}
+			if self.quirks.load_store_increments_i {
+				self.i += x as u16 + 1;
+			}
 		} else if (inst & 0xF0FF) == 0xF065 { // LD Vx, [I]
 			let x = ((inst >> 8) & 0x0F) as usize;
 			let addr = self.i as usize;
 			for i in 0..(x+1) {
 				self.v[i] = self.memory[addr + i];
 			}
+			if self.quirks.load_store_increments_i {
+				self.i += x as u16 + 1;
+			}
 		} else {
 			panic!("{inst:#X} at addr {:#X}", self.pc);
 		}
+
+		self.prev_keys = self.keys;
 	}
 
 	fn next_instruction(&mut self) -> u16 {
@@ -183,4 +426,254 @@ impl Chip {
 		self.pc += 2;
 		(&self.memory[p..(p+2)]).read_u16::<BigEndian>().unwrap()
 	}
+
+	// xorshift64: cheap, seedable PRNG so CXNN output is deterministic in tests.
+	fn next_rand_byte(&mut self) -> u8 {
+		let mut x = self.seed;
+		x ^= x << 13;
+		x ^= x >> 7;
+		x ^= x << 17;
+		self.seed = x;
+		x as u8
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fx0a_rewinds_while_held_and_latches_only_on_key_down_edge() {
+		let mut chip = Chip::new();
+		chip.memory[0x200..0x204].copy_from_slice(&[0x00, 0xE0, 0xF0, 0x0A]); // CLS ; LD V0, K
+		chip.pc = 0x200;
+
+		chip.key_down(5);
+		chip.tick(); // CLS; also lets prev_keys catch up to the held key
+		assert_eq!(chip.pc, 0x202);
+
+		// Key is held steady across several ticks: FX0A must rewind pc and
+		// not latch, since there is no low->high edge.
+		for _ in 0..3 {
+			chip.tick();
+			assert_eq!(chip.pc, 0x202);
+			assert_eq!(chip.v[0], 0);
+		}
+
+		chip.key_up(5);
+		chip.tick();
+		assert_eq!(chip.pc, 0x202);
+
+		chip.key_down(5); // low -> high edge
+		chip.tick();
+		assert_eq!(chip.v[0], 5);
+		assert_eq!(chip.pc, 0x204);
+	}
+
+	#[test]
+	fn skp_and_sknp_mask_vx_to_four_bits_before_indexing_keys() {
+		let mut chip = Chip::new();
+		// LD V0,0xFF ; SKP V0 (skips the filler CLS) ; CLS (filler, skipped) ; SKNP V0
+		chip.memory[0x200..0x208].copy_from_slice(&[0x60, 0xFF, 0xE0, 0x9E, 0x00, 0xE0, 0xE0, 0xA1]);
+		chip.pc = 0x200;
+		chip.key_down(0x0F); // 0xFF & 0x0F == 0x0F
+
+		chip.tick(); // LD V0, 0xFF
+		assert_eq!(chip.pc, 0x202);
+
+		chip.tick(); // SKP V0: key 0x0F is down, so this must skip
+		assert_eq!(chip.pc, 0x206);
+
+		chip.tick(); // SKNP V0: key 0x0F is down, so this must not skip
+		assert_eq!(chip.pc, 0x208);
+	}
+
+	#[test]
+	fn drw_clips_sprite_at_right_and_bottom_edges_without_wrapping() {
+		let mut chip = Chip::new();
+		chip.memory[0x300] = 0xFF; // a full row of set bits
+		chip.memory[0x301] = 0xFF; // second row, lands on row 32
+		chip.i = 0x300;
+		chip.v[0] = 60; // x: columns 60..68 requested, only 60..64 exist
+		chip.v[1] = 31; // y: last valid row, the sprite's second row (32) is out of bounds
+		chip.memory[0x200..0x202].copy_from_slice(&[0xD0, 0x12]); // DRW V0, V1, 2
+		chip.pc = 0x200;
+
+		chip.tick();
+
+		for col in 60..64 {
+			assert!(chip.screen[31 * 64 + col]);
+		}
+		// Columns past 63 must be clipped, not wrapped to column 0.
+		assert!(!chip.screen[31 * 64]);
+		// Rows past 31 must be clipped, not wrapped to row 0.
+		for col in 60..64 {
+			assert!(!chip.screen[col]);
+		}
+	}
+
+	#[test]
+	fn drw_sets_vf_on_collision_and_clears_when_none() {
+		let mut chip = Chip::new();
+		chip.memory[0x300] = 0b1111_0000;
+		chip.i = 0x300;
+		chip.v[0] = 0;
+		chip.v[1] = 0;
+		chip.memory[0x200..0x202].copy_from_slice(&[0xD0, 0x11]); // DRW V0, V1, 1
+		chip.pc = 0x200;
+
+		chip.tick();
+		assert_eq!(chip.v[0xF], 0);
+		assert!(chip.screen[0]);
+
+		chip.pc = 0x200; // redraw the identical sprite at the same spot
+		chip.tick();
+		assert_eq!(chip.v[0xF], 1); // XORing an already-set pixel off is a collision
+		assert!(!chip.screen[0]);
+	}
+
+	#[test]
+	fn shr_quirk_selects_source_register() {
+		// COSMAC VIP (default): SHR Vx, Vy shifts Vy into Vx.
+		let mut vip = Chip::new();
+		vip.v[1] = 0b0000_0011;
+		vip.v[0] = 0b1111_0000;
+		vip.memory[0x200..0x202].copy_from_slice(&[0x80, 0x16]); // SHR V0, V1
+		vip.pc = 0x200;
+		vip.tick();
+		assert_eq!(vip.v[0], 0b0000_0001);
+		assert_eq!(vip.v[0xF], 1);
+
+		// SCHIP: SHR Vx shifts Vx in place; Vy is ignored.
+		let mut schip = Chip::new();
+		schip.set_quirks(Quirks::schip());
+		schip.v[1] = 0b0000_0011;
+		schip.v[0] = 0b1111_0000;
+		schip.memory[0x200..0x202].copy_from_slice(&[0x80, 0x16]); // SHR V0, V1
+		schip.pc = 0x200;
+		schip.tick();
+		assert_eq!(schip.v[0], 0b0111_1000);
+		assert_eq!(schip.v[0xF], 0);
+	}
+
+	#[test]
+	fn load_store_quirk_controls_i_increment() {
+		// COSMAC VIP (default): FX55/FX65 increment I by x + 1.
+		let mut vip = Chip::new();
+		vip.v[0] = 1;
+		vip.v[1] = 2;
+		vip.i = 0x300;
+		vip.memory[0x200..0x202].copy_from_slice(&[0xF1, 0x55]); // LD [I], V1
+		vip.pc = 0x200;
+		vip.tick();
+		assert_eq!(vip.i, 0x302);
+
+		// SCHIP: I is left unchanged.
+		let mut schip = Chip::new();
+		schip.set_quirks(Quirks::schip());
+		schip.v[0] = 1;
+		schip.v[1] = 2;
+		schip.i = 0x300;
+		schip.memory[0x200..0x202].copy_from_slice(&[0xF1, 0x55]); // LD [I], V1
+		schip.pc = 0x200;
+		schip.tick();
+		assert_eq!(schip.i, 0x300);
+	}
+
+	#[test]
+	fn bnnn_quirk_selects_jump_register() {
+		// COSMAC VIP (default): BNNN jumps to addr + V0.
+		let mut vip = Chip::new();
+		vip.v[0] = 0x10;
+		vip.v[2] = 0x99; // ignored under VIP
+		vip.memory[0x200..0x202].copy_from_slice(&[0xB2, 0x00]); // JP V0, 0x200
+		vip.pc = 0x200;
+		vip.tick();
+		assert_eq!(vip.pc, 0x210);
+
+		// SCHIP: BNNN jumps to addr + Vx, where x is addr's high nibble.
+		let mut schip = Chip::new();
+		schip.set_quirks(Quirks::schip());
+		schip.v[0] = 0x10; // ignored under SCHIP
+		schip.v[2] = 0x99; // addr 0x200's high nibble selects V2
+		schip.memory[0x200..0x202].copy_from_slice(&[0xB2, 0x00]); // JP V2, 0x200
+		schip.pc = 0x200;
+		schip.tick();
+		assert_eq!(schip.pc, (0x200 + 0x99) & 0x0FFF);
+	}
+
+	#[test]
+	fn rnd_is_deterministic_for_a_given_seed() {
+		let mut chip = Chip::with_seed(1234);
+		chip.memory[0x200..0x204].copy_from_slice(&[0xC0, 0xFF, 0xC1, 0x0F]);
+		chip.pc = 0x200;
+
+		chip.tick(); // RND V0, 0xFF
+		chip.tick(); // RND V1, 0x0F
+
+		assert_eq!(chip.v[0], 91);
+		assert_eq!(chip.v[1], 11);
+	}
+
+	#[test]
+	fn rnd_sequence_matches_across_chips_with_same_seed() {
+		let program = [0xC0, 0xFF, 0xC1, 0xFF];
+		let run = |seed: u64| {
+			let mut chip = Chip::with_seed(seed);
+			chip.memory[0x200..0x204].copy_from_slice(&program);
+			chip.pc = 0x200;
+			chip.tick();
+			chip.tick();
+			(chip.v[0], chip.v[1])
+		};
+
+		assert_eq!(run(0xDEAD_BEEF), run(0xDEAD_BEEF));
+	}
+
+	#[test]
+	fn save_state_round_trips_full_machine_state() {
+		let mut chip = Chip::with_seed(7);
+		chip.memory[0x200..0x204].copy_from_slice(&[0x60, 0x2A, 0xA2, 0x34]);
+		chip.pc = 0x200;
+		chip.tick(); // LD V0, 0x2A
+		chip.tick(); // LD I, 0x234
+		chip.delay_timer = 12;
+		chip.sound_timer = 34;
+		chip.stack[0] = 0x400;
+		chip.sp = 1;
+		chip.screen[5] = true;
+
+		let saved = chip.save_state();
+
+		let mut restored = Chip::new();
+		restored.load_state(&saved).unwrap();
+
+		assert_eq!(restored.memory, chip.memory);
+		assert_eq!(restored.v, chip.v);
+		assert_eq!(restored.i, chip.i);
+		assert_eq!(restored.delay_timer, chip.delay_timer);
+		assert_eq!(restored.sound_timer, chip.sound_timer);
+		assert_eq!(restored.stack, chip.stack);
+		assert_eq!(restored.sp, chip.sp);
+		assert_eq!(restored.pc, chip.pc);
+		assert_eq!(restored.screen, chip.screen);
+	}
+
+	#[test]
+	fn load_state_rejects_bad_magic() {
+		let mut chip = Chip::new();
+		let err = chip.load_state(&[0, 0, 0, 0, 1]).unwrap_err();
+		assert!(matches!(err, LoadStateError::BadMagic));
+	}
+
+	#[test]
+	fn load_state_rejects_out_of_range_stack_pointer() {
+		let mut chip = Chip::new();
+		let mut saved = chip.save_state();
+		let sp_offset = SAVE_MAGIC.len() + 1 + chip.memory.len() + chip.v.len() + 6 + chip.stack.len() * 2;
+		saved[sp_offset] = 250;
+
+		let err = chip.load_state(&saved).unwrap_err();
+		assert!(matches!(err, LoadStateError::InvalidStackPointer(250)));
+	}
 }
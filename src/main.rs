@@ -1,3 +1,4 @@
+mod asm;
 mod chip;
 
 fn main() {
@@ -18,4 +19,51 @@ fn main() {
             println!("");
         }
     }
+
+    demo_input_and_quirks();
+    demo_assembler();
+}
+
+// Exercises the keypad, timer, quirks and save-state API that a front-end
+// would drive each frame: feed key events, step the 60 Hz timers, pick a
+// quirks profile, and snapshot/restore the machine.
+fn demo_input_and_quirks() {
+    let mut chip = chip::Chip::new();
+    chip.set_quirks(chip::Quirks::schip());
+
+    chip.key_down(0x5);
+    chip.tick_timers();
+    chip.key_up(0x5);
+
+    println!("beeping: {}", chip.is_beeping());
+
+    let snapshot = chip.save_state();
+    if let Err(err) = chip.load_state(&snapshot) {
+        match err {
+            chip::LoadStateError::BadMagic => println!("load_state error: bad magic"),
+            chip::LoadStateError::UnsupportedVersion(v) => {
+                println!("load_state error: unsupported version {v}");
+            }
+            chip::LoadStateError::Truncated => println!("load_state error: truncated"),
+            chip::LoadStateError::InvalidStackPointer(sp) => {
+                println!("load_state error: invalid stack pointer {sp}");
+            }
+        }
+    }
+}
+
+// Exercises the assembler/disassembler round-trip.
+fn demo_assembler() {
+    let source = "start:\n\tCLS\n\tJP start\n";
+    let bytes = match asm::assemble(source) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            println!("assemble error: {err}");
+            return;
+        }
+    };
+
+    for (addr, opcode, mnemonic) in asm::disassemble(&bytes, 0x200) {
+        println!("{addr:#06X} {opcode:#06X} {mnemonic}");
+    }
 }